@@ -98,8 +98,35 @@ mod unit_tests {
                 ]
             );
         }
+
+        #[test]
+        fn from_contents_sizes_columns_and_rows_from_their_widest_cell() {
+            let contents = vec![
+                vec![String::from("a"), String::from("two\nlines")],
+                vec![String::from("longer")],
+            ];
+
+            let grid = GridSizes::from_contents(&contents, 1);
+
+            assert_eq!(grid.widths, vec![10usize, 9usize]);
+            assert_eq!(grid.heights, vec![4usize, 3usize]);
+        }
+
+        #[test]
+        fn from_contents_of_no_rows_renders_a_single_empty_cell_instead_of_panicking() {
+            let grid = GridSizes::from_contents(&[], 0);
+
+            assert_eq!(
+                grid.to_table(),
+                vec![
+                    String::from("┏┓"),
+                    String::from("┃┃"),
+                    String::from("┗┛"),
+                ]
+            );
+        }
     }
-    
+
     mod string_grid_tests {
         use crate::table_formatting::StringTable;
         #[test]
@@ -177,6 +204,347 @@ mod unit_tests {
     
     }
     
+    mod border_style_tests {
+        use crate::table_formatting::grid_formatting::{BorderStyle, GridSizes};
+        use crate::table_formatting::string_grid::generate_string_grid_with_style;
+        use crate::table_formatting::StringTable;
+
+        #[test]
+        fn presets_swap_the_glyphs_not_the_dimensions() {
+            let grid = GridSizes {
+                widths: vec![3usize, 4usize],
+                heights: vec![3usize],
+            };
+
+            assert_eq!(
+                grid.to_table_with_style(&BorderStyle::rounded()),
+                vec![
+                    String::from("╭─┬──╮"),
+                    String::from("│ │  │"),
+                    String::from("╰─┴──╯"),
+                ]
+            );
+
+            assert_eq!(
+                grid.to_table_with_style(&BorderStyle::ascii()),
+                vec![
+                    String::from("+-+--+"),
+                    String::from("| |  |"),
+                    String::from("+-+--+"),
+                ]
+            );
+        }
+
+        #[test]
+        fn default_style_matches_the_historical_heavy_set() {
+            let grid = GridSizes {
+                widths: vec![3usize],
+                heights: vec![3usize],
+            };
+
+            assert_eq!(grid.to_table(), grid.to_table_with_style(&BorderStyle::default()));
+        }
+
+        #[test]
+        fn generate_string_grid_with_style_double_lines() {
+            let values = vec![[String::from("Hi")]];
+
+            assert_eq!(
+                generate_string_grid_with_style(&values, &BorderStyle::double()),
+                vec![
+                    String::from("╔══╗"),
+                    String::from("║Hi║"),
+                    String::from("╚══╝"),
+                ]
+            );
+        }
+    }
+
+    mod alignment_tests {
+        use crate::table_formatting::alignment::{Alignment, ColumnAlignments, HorizontalAlignment, VerticalAlignment};
+        use crate::table_formatting::string_grid::generate_string_grid_with_alignment;
+
+        #[test]
+        fn per_column_horizontal_alignment() {
+            let values = vec![
+                [String::from("Name"), String::from("Count")],
+                [String::from("x"), String::from("1000")],
+                [String::from("yy"), String::from("5")],
+            ];
+
+            let alignments = ColumnAlignments::new(Alignment::default()).set_column(
+                1,
+                Alignment { horizontal: HorizontalAlignment::Right, vertical: VerticalAlignment::Top },
+            );
+
+            assert_eq!(
+                generate_string_grid_with_alignment(&values, &alignments),
+                vec![
+                    String::from("┏━━━━┳━━━━━┓"),
+                    String::from("┃Name┃Count┃"),
+                    String::from("┣━━━━╋━━━━━┫"),
+                    String::from("┃x   ┃ 1000┃"),
+                    String::from("┣━━━━╋━━━━━┫"),
+                    String::from("┃yy  ┃    5┃"),
+                    String::from("┗━━━━┻━━━━━┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn center_alignment_splits_padding_both_ways() {
+            let values = vec![
+                [String::from("a\nb\nc"), String::from("mid")],
+                [String::from("d"), String::from("midpoint")],
+            ];
+
+            let alignments = ColumnAlignments::new(Alignment::default()).set_column(
+                1,
+                Alignment { horizontal: HorizontalAlignment::Center, vertical: VerticalAlignment::Center },
+            );
+
+            assert_eq!(
+                generate_string_grid_with_alignment(&values, &alignments),
+                vec![
+                    String::from("┏━┳━━━━━━━━┓"),
+                    String::from("┃a┃        ┃"),
+                    String::from("┃b┃  mid   ┃"),
+                    String::from("┃c┃        ┃"),
+                    String::from("┣━╋━━━━━━━━┫"),
+                    String::from("┃d┃midpoint┃"),
+                    String::from("┗━┻━━━━━━━━┛"),
+                ]
+            );
+        }
+    }
+
+    mod styled_cell_tests {
+        use crate::string_stylizing::{StringColor, StringStyle};
+        use crate::table_formatting::string_grid::generate_string_grid_with_styles;
+        use crate::table_formatting::styled_cell::StyledCell;
+
+        #[test]
+        fn styled_cells_measure_by_visible_width_not_escape_bytes() {
+            let values = vec![[
+                StyledCell::with_style("hi", StringStyle::default().set_text_color(&StringColor::Red)),
+                StyledCell::new("plain"),
+            ]];
+
+            assert_eq!(
+                generate_string_grid_with_styles(&values),
+                vec![
+                    String::from("┏━━┳━━━━━┓"),
+                    format!("┃{}┃plain┃", "\x1b[31mhi\x1b[0m"),
+                    String::from("┗━━┻━━━━━┛"),
+                ]
+            );
+        }
+    }
+
+    mod span_tests {
+        use crate::table_formatting::span::{generate_spanned_grid, SpannedCell};
+
+        #[test]
+        fn colspan_merges_the_top_border_and_widens_to_fit() {
+            let rows = vec![
+                vec![SpannedCell::spanning("Title", 2, 1)],
+                vec![SpannedCell::new("a"), SpannedCell::new("bb")],
+            ];
+
+            assert_eq!(
+                generate_spanned_grid(&rows, 2),
+                vec![
+                    String::from("┏━━━━━┓"),
+                    String::from("┃Title┃"),
+                    String::from("┣━━┳━━┫"),
+                    String::from("┃a ┃bb┃"),
+                    String::from("┗━━┻━━┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn rowspan_removes_the_separator_it_crosses() {
+            let rows = vec![
+                vec![SpannedCell::spanning("tall", 1, 2), SpannedCell::new("x")],
+                vec![SpannedCell::new("y")],
+            ];
+
+            assert_eq!(
+                generate_spanned_grid(&rows, 2),
+                vec![
+                    String::from("┏━━━━┳━┓"),
+                    String::from("┃tall┃x┃"),
+                    String::from("┃    ┣━┫"),
+                    String::from("┃    ┃y┃"),
+                    String::from("┗━━━━┻━┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn a_cell_can_span_columns_and_rows_at_once() {
+            let rows = vec![
+                vec![SpannedCell::spanning("big", 2, 2), SpannedCell::new("c")],
+                vec![SpannedCell::new("d")],
+                vec![SpannedCell::new("e"), SpannedCell::new("f"), SpannedCell::new("g")],
+            ];
+
+            assert_eq!(
+                generate_spanned_grid(&rows, 3),
+                vec![
+                    String::from("┏━━━┳━┓"),
+                    String::from("┃big┃c┃"),
+                    String::from("┃   ┣━┫"),
+                    String::from("┃   ┃d┃"),
+                    String::from("┣━┳━╋━┫"),
+                    String::from("┃e┃f┃g┃"),
+                    String::from("┗━┻━┻━┛"),
+                ]
+            );
+        }
+    }
+
+    mod padding_tests {
+        use crate::table_formatting::padding::{ColumnPadding, Padding};
+        use crate::table_formatting::string_grid::generate_string_grid_with_padding;
+
+        #[test]
+        fn padding_reserves_blank_space_on_either_side() {
+            let values = vec![[String::from("x"), String::from("y")]];
+            let padding = ColumnPadding::new(Padding::default()).set_column(1, Padding { left: 2, right: 1 });
+
+            assert_eq!(
+                generate_string_grid_with_padding(&values, &padding),
+                vec![
+                    String::from("┏━┳━━━━┓"),
+                    String::from("┃x┃  y ┃"),
+                    String::from("┗━┻━━━━┛"),
+                ]
+            );
+        }
+    }
+
+    mod column_width_tests {
+        use crate::table_formatting::column_width::{ColumnMaxWidths, OverflowPolicy};
+        use crate::table_formatting::string_grid::generate_string_grid_with_max_width;
+
+        #[test]
+        fn truncate_appends_an_ellipsis_within_the_limit() {
+            let values = vec![[String::from("a very long sentence")]];
+            let max_widths = ColumnMaxWidths::new(OverflowPolicy::Truncate).set_default(8);
+
+            assert_eq!(
+                generate_string_grid_with_max_width(&values, &max_widths),
+                vec![
+                    String::from("┏━━━━━━━━┓"),
+                    String::from("┃a very …┃"),
+                    String::from("┗━━━━━━━━┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn truncate_falls_back_to_blank_when_too_narrow_for_the_ellipsis() {
+            let values = vec![[String::from("hello")]];
+            let max_widths = ColumnMaxWidths::new(OverflowPolicy::Truncate).set_default(0);
+
+            assert_eq!(
+                generate_string_grid_with_max_width(&values, &max_widths),
+                vec![
+                    String::from("┏┓"),
+                    String::from("┃┃"),
+                    String::from("┗┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn wrap_reflows_words_and_grows_the_row_height() {
+            let values = vec![[String::from("one two three")]];
+            let max_widths = ColumnMaxWidths::new(OverflowPolicy::Wrap).set_default(7);
+
+            assert_eq!(
+                generate_string_grid_with_max_width(&values, &max_widths),
+                vec![
+                    String::from("┏━━━━━━━┓"),
+                    String::from("┃one two┃"),
+                    String::from("┃three  ┃"),
+                    String::from("┗━━━━━━━┛"),
+                ]
+            );
+        }
+
+        #[test]
+        fn wrap_hard_splits_a_single_overlong_word() {
+            let values = vec![[String::from("supercalifragilistic")]];
+            let max_widths = ColumnMaxWidths::new(OverflowPolicy::Wrap).set_default(6);
+
+            assert_eq!(
+                generate_string_grid_with_max_width(&values, &max_widths),
+                vec![
+                    String::from("┏━━━━━━┓"),
+                    String::from("┃superc┃"),
+                    String::from("┃alifra┃"),
+                    String::from("┃gilist┃"),
+                    String::from("┃ic    ┃"),
+                    String::from("┗━━━━━━┛"),
+                ]
+            );
+        }
+    }
+
+    mod display_width_tests {
+        use crate::table_formatting::string_grid::generate_string_grid;
+        use crate::table_formatting::display_width::display_width;
+
+        // Full-width CJK characters and combining marks occupy 2 and 0
+        // terminal columns respectively, so a naive char count would drift
+        // the borders out of alignment with the rest of the row.
+        #[test]
+        fn wide_and_combining_characters_keep_borders_aligned() {
+            let rows = vec![
+                [String::from("名前"), String::from("Robert")],
+                [String::from("e\u{0301}migre"), String::from("🎉party")],
+            ];
+
+            let table = generate_string_grid(&rows);
+            let expected_width = display_width(&table[0]);
+
+            for line in &table {
+                assert_eq!(display_width(line), expected_width);
+            }
+        }
+    }
+
+    mod expanded_tests {
+        use crate::table_formatting::expanded::ExpandedTable;
+        use crate::table_formatting::StringTable;
+
+        #[test]
+        fn renders_one_record_per_block() {
+            let table = ExpandedTable {
+                records: vec![
+                    vec![(String::from("id"), String::from("1")), (String::from("bio"), String::from("line one\nline two"))],
+                    vec![(String::from("id"), String::from("2")), (String::from("bio"), String::from("short"))],
+                ],
+            };
+
+            assert_eq!(
+                table.to_table(),
+                vec![
+                    String::from("-[ RECORD 1 ]+---------"),
+                    String::from("id           | 1"),
+                    String::from("bio          | line one"),
+                    String::from("             | line two"),
+                    String::from("-[ RECORD 2 ]+------"),
+                    String::from("id           | 2"),
+                    String::from("bio          | short"),
+                ]
+            );
+        }
+    }
+
     mod string_stylizer_tests {
         #[test]
         fn test_string_formatting() {
@@ -198,5 +566,19 @@ mod unit_tests {
     
             assert_eq!(result, "\x1b[1;9;5;34mhello!\u{1b}[0m")
         }
+
+        #[test]
+        fn test_extended_color_formatting() {
+            use crate::string_stylizing::*;
+
+            let result = crate::string_stylizing::format_string(
+                &String::from("hello!"),
+                &StringStyle::default()
+                    .set_text_color(&StringColor::Rgb(255, 0, 255))
+                    .set_background_color(&StringColor::Ansi256(22)),
+            );
+
+            assert_eq!(result, "\x1b[38;2;255;0;255;48;5;22mhello!\u{1b}[0m")
+        }
     }
 }
\ No newline at end of file
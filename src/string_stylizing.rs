@@ -70,7 +70,8 @@ impl Default for StringStyle {
     }
 }
 
-/// All basic colors as defined by SGR
+/// All basic colors as defined by SGR, plus the 256-color palette
+/// (`Ansi256`) and 24-bit true color (`Rgb`) extensions
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum StringColor {
@@ -90,6 +91,10 @@ pub enum StringColor {
     LightMagenta,
     LightCyan,
     BrightWhite,
+    /// One of the 256 indexed colors (`38;5;n` / `48;5;n`)
+    Ansi256(u8),
+    /// A 24-bit true color (`38;2;r;g;b` / `48;2;r;g;b`)
+    Rgb(u8, u8, u8),
     None,
 }
 
@@ -137,9 +142,9 @@ pub enum StringBlinkSpeed {
 /// Styles can have multiple changes applied
 /// ```
 /// use formatted_debug::string_stylizing::*;
-/// 
+///
 /// let result = format_string(
-///     &String::from("hello!"), 
+///     &String::from("hello!"),
 ///     &StringStyle::default()
 ///         .set_text_color(&StringColor::Blue)
 ///         .set_bold(true)
@@ -148,7 +153,21 @@ pub enum StringBlinkSpeed {
 /// );
 /// assert_eq!(result, "\x1b[1;9;5;34mhello!\u{1b}[0m")
 /// ```
-pub fn format_string(unformatted_string: &String, style: &StringStyle) -> String {    
+///
+/// ## Extended Color
+/// `Ansi256` and `Rgb` reach beyond the 16 named SGR colors
+/// ```
+/// use formatted_debug::string_stylizing::*;
+///
+/// let result = format_string(
+///     &String::from("hello!"),
+///     &StringStyle::default()
+///         .set_text_color(&StringColor::Rgb(255, 0, 255))
+///         .set_background_color(&StringColor::Ansi256(22))
+/// );
+/// assert_eq!(result, "\x1b[38;2;255;0;255;48;5;22mhello!\u{1b}[0m")
+/// ```
+pub fn format_string(unformatted_string: &String, style: &StringStyle) -> String {
     if style.eq(&StringStyle::default()) {
         // No formatting required, return input string
         return unformatted_string.to_string();
@@ -176,44 +195,48 @@ pub fn format_string(unformatted_string: &String, style: &StringStyle) -> String
     {
         use StringColor::*;
 
-        prepended_formatting.push_str(match style.color {
-            Black => "30;",
-            Red => "31;",
-            Green => "32;",
-            Yellow => "33;",
-            Blue => "34;",
-            Magenta => "35;",
-            Cyan => "36;",
-            White => "37;",
-            Gray => "90;",
-            Pink => "91;",
-            Lime => "92;",
-            BrightYellow => "93;",
-            LightBlue => "94;",
-            LightMagenta => "95;",
-            LightCyan => "96;",
-            BrightWhite => "97;",
-            None => "",
+        prepended_formatting.push_str(&match style.color {
+            Black => String::from("30;"),
+            Red => String::from("31;"),
+            Green => String::from("32;"),
+            Yellow => String::from("33;"),
+            Blue => String::from("34;"),
+            Magenta => String::from("35;"),
+            Cyan => String::from("36;"),
+            White => String::from("37;"),
+            Gray => String::from("90;"),
+            Pink => String::from("91;"),
+            Lime => String::from("92;"),
+            BrightYellow => String::from("93;"),
+            LightBlue => String::from("94;"),
+            LightMagenta => String::from("95;"),
+            LightCyan => String::from("96;"),
+            BrightWhite => String::from("97;"),
+            Ansi256(color) => format!("38;5;{};", color),
+            Rgb(red, green, blue) => format!("38;2;{};{};{};", red, green, blue),
+            None => String::new(),
         });
-        
-        prepended_formatting.push_str(match style.background_color {
-            Black => "40;",
-            Red => "41;",
-            Green => "42;",
-            Yellow => "43;",
-            Blue => "44;",
-            Magenta => "45;",
-            Cyan => "46;",
-            White => "47;",
-            Gray => "100;",
-            Pink => "101;",
-            Lime => "102;",
-            BrightYellow => "103;",
-            LightBlue => "104;",
-            LightMagenta => "105;",
-            LightCyan => "106;",
-            BrightWhite => "107;",
-            None => "",
+
+        prepended_formatting.push_str(&match style.background_color {
+            Black => String::from("40;"),
+            Red => String::from("41;"),
+            Green => String::from("42;"),
+            Yellow => String::from("43;"),
+            Blue => String::from("44;"),
+            Magenta => String::from("45;"),
+            Cyan => String::from("46;"),
+            White => String::from("47;"),
+            Gray => String::from("100;"),
+            Pink => String::from("101;"),
+            Lime => String::from("102;"),
+            BrightYellow => String::from("103;"),
+            LightBlue => String::from("104;"),
+            LightMagenta => String::from("105;"),
+            LightCyan => String::from("106;"),
+            BrightWhite => String::from("107;"),
+            Ansi256(color) => format!("48;5;{};", color),
+            Rgb(red, green, blue) => format!("48;2;{};{};{};", red, green, blue),
+            None => String::new(),
         });
     }
 
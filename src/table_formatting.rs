@@ -3,8 +3,15 @@
 //! `table_formatting` is a module to format values 
 //! into vectors of strings which represent tables
 
+pub mod alignment;
+pub mod column_width;
+pub mod display_width;
+pub mod expanded;
 pub mod grid_formatting;
+pub mod padding;
+pub mod span;
 pub mod string_grid;
+pub mod styled_cell;
 
 pub trait StringTable {
     fn to_table(&self) -> Vec<String>;
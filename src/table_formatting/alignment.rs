@@ -0,0 +1,89 @@
+//! Where a cell's content sits within the space its column and row allot it.
+
+/// Horizontal placement of a cell's content within its column.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical placement of a cell's content within its row.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VerticalAlignment {
+    Top,
+    Center,
+    Bottom,
+}
+
+/// A cell's horizontal and vertical placement within the space its column
+/// and row allot it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Alignment {
+    pub horizontal: HorizontalAlignment,
+    pub vertical: VerticalAlignment,
+}
+
+impl Default for Alignment {
+    /// Left/Top, matching the grid's historical behavior.
+    fn default() -> Alignment {
+        Alignment {
+            horizontal: HorizontalAlignment::Left,
+            vertical: VerticalAlignment::Top,
+        }
+    }
+}
+
+/// Per-column cell alignment: a default applied to every column, with
+/// optional overrides for specific column indices.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::alignment::{
+///     Alignment, ColumnAlignments, HorizontalAlignment, VerticalAlignment,
+/// };
+///
+/// let alignments = ColumnAlignments::new(Alignment::default()).set_column(
+///     1,
+///     Alignment { horizontal: HorizontalAlignment::Right, vertical: VerticalAlignment::Top },
+/// );
+///
+/// assert_eq!(alignments.get(0), Alignment::default());
+/// assert_eq!(alignments.get(1).horizontal, HorizontalAlignment::Right);
+/// ```
+#[derive(Clone)]
+pub struct ColumnAlignments {
+    default: Alignment,
+    overrides: Vec<(usize, Alignment)>,
+}
+
+impl ColumnAlignments {
+    /// Creates a `ColumnAlignments` applying `default` to every column.
+    pub fn new(default: Alignment) -> ColumnAlignments {
+        ColumnAlignments { default, overrides: Vec::new() }
+    }
+
+    /// Overrides the alignment used for `column`, replacing any previous
+    /// override for that column.
+    pub fn set_column(mut self, column: usize, alignment: Alignment) -> ColumnAlignments {
+        self.overrides.retain(|(existing_column, _)| *existing_column != column);
+        self.overrides.push((column, alignment));
+        self
+    }
+
+    /// Returns the alignment to use for `column`: its override if one was
+    /// set, otherwise the default.
+    pub fn get(&self, column: usize) -> Alignment {
+        self.overrides
+            .iter()
+            .find(|(existing_column, _)| *existing_column == column)
+            .map(|(_, alignment)| *alignment)
+            .unwrap_or(self.default)
+    }
+}
+
+impl Default for ColumnAlignments {
+    /// Left/Top for every column, matching the grid's historical behavior.
+    fn default() -> ColumnAlignments {
+        ColumnAlignments::new(Alignment::default())
+    }
+}
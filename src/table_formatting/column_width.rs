@@ -0,0 +1,177 @@
+//! Bounding how wide a column is allowed to grow, and what happens to cell
+//! content that doesn't fit.
+
+use crate::table_formatting::display_width::{char_display_width, display_width};
+
+/// How to handle a cell whose content is wider than its column's max width.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OverflowPolicy {
+    /// Cut each line to the limit and append an ellipsis.
+    Truncate,
+    /// Reflow the content into multiple lines that each fit the limit.
+    Wrap,
+}
+
+/// A maximum column width, applied to every column by default with optional
+/// per-column overrides, plus the [`OverflowPolicy`] used when content
+/// doesn't fit.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::column_width::{ColumnMaxWidths, OverflowPolicy};
+///
+/// let max_widths = ColumnMaxWidths::new(OverflowPolicy::Truncate)
+///     .set_default(10)
+///     .set_column(1, 20);
+///
+/// assert_eq!(max_widths.get(0), Some(10));
+/// assert_eq!(max_widths.get(1), Some(20));
+/// ```
+#[derive(Clone)]
+pub struct ColumnMaxWidths {
+    default: Option<usize>,
+    overrides: Vec<(usize, usize)>,
+    policy: OverflowPolicy,
+}
+
+impl ColumnMaxWidths {
+    /// Creates a `ColumnMaxWidths` with no limit set for any column yet.
+    pub fn new(policy: OverflowPolicy) -> ColumnMaxWidths {
+        ColumnMaxWidths { default: None, overrides: Vec::new(), policy }
+    }
+
+    /// Sets the max width applied to every column that has no override.
+    pub fn set_default(mut self, max_width: usize) -> ColumnMaxWidths {
+        self.default = Some(max_width);
+        self
+    }
+
+    /// Overrides the max width used for `column`, replacing any previous
+    /// override for that column.
+    pub fn set_column(mut self, column: usize, max_width: usize) -> ColumnMaxWidths {
+        self.overrides.retain(|(existing_column, _)| *existing_column != column);
+        self.overrides.push((column, max_width));
+        self
+    }
+
+    /// Returns the max width to use for `column`, if any: its override if
+    /// one was set, otherwise the default.
+    pub fn get(&self, column: usize) -> Option<usize> {
+        self.overrides
+            .iter()
+            .find(|(existing_column, _)| *existing_column == column)
+            .map(|(_, max_width)| *max_width)
+            .or(self.default)
+    }
+
+    /// The policy used when a cell's content doesn't fit its max width.
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+}
+
+/// Applies `max_width`'s [`OverflowPolicy`] to `text`, line by line, so the
+/// result has no line wider than `max_width`, with two exceptions under
+/// `Wrap`: a `max_width` of 0 leaves each word whole on a line of its own,
+/// and a single character wider than `max_width` gets a line of its own.
+pub(crate) fn bound_to_width(text: &str, max_width: usize, policy: OverflowPolicy) -> String {
+    match policy {
+        OverflowPolicy::Truncate => text.split('\n').map(|line| truncate_line(line, max_width)).collect::<Vec<_>>().join("\n"),
+        OverflowPolicy::Wrap => text.split('\n').map(|line| wrap_line(line, max_width)).collect::<Vec<_>>().join("\n"),
+    }
+}
+
+/// Cuts `line` to `max_width` display columns and appends an ellipsis,
+/// accounting for the ellipsis's own width so the result never overshoots.
+/// Falls back to a blank line if `max_width` is too narrow to fit even the
+/// ellipsis.
+fn truncate_line(line: &str, max_width: usize) -> String {
+    if display_width(line) <= max_width {
+        return line.to_string();
+    }
+
+    const ELLIPSIS: char = '…';
+    let ellipsis_width = char_display_width(ELLIPSIS);
+
+    if max_width < ellipsis_width {
+        return String::new();
+    }
+
+    let budget = max_width - ellipsis_width;
+    let mut truncated = String::new();
+    let mut width = 0usize;
+
+    for current_char in line.chars() {
+        let char_width = char_display_width(current_char);
+        if width + char_width > budget {
+            break;
+        }
+        truncated.push(current_char);
+        width += char_width;
+    }
+
+    truncated.push(ELLIPSIS);
+    truncated
+}
+
+/// Greedily packs whitespace-separated words from `line` onto lines no
+/// wider than `max_width`, hard-splitting any single word that is itself
+/// too long to fit a line on its own.
+fn wrap_line(line: &str, max_width: usize) -> String {
+    let mut wrapped_lines = Vec::new();
+    let mut current_line = String::new();
+    let mut current_width = 0usize;
+
+    for word in line.split_whitespace() {
+        for chunk in hard_split_word(word, max_width) {
+            let chunk_width = display_width(&chunk);
+            let space_width = if current_line.is_empty() { 0 } else { 1 };
+
+            if current_width + space_width + chunk_width > max_width && !current_line.is_empty() {
+                wrapped_lines.push(std::mem::take(&mut current_line));
+                current_width = 0;
+            }
+
+            if !current_line.is_empty() {
+                current_line.push(' ');
+                current_width += 1;
+            }
+            current_line.push_str(&chunk);
+            current_width += chunk_width;
+        }
+    }
+
+    if !current_line.is_empty() || wrapped_lines.is_empty() {
+        wrapped_lines.push(current_line);
+    }
+
+    wrapped_lines.join("\n")
+}
+
+/// Splits `word` into display-width-bounded chunks of at most `max_width`
+/// columns each. Returns `word` unchanged if it already fits or if
+/// `max_width` is 0; a character wider than `max_width` forms a chunk of its
+/// own.
+fn hard_split_word(word: &str, max_width: usize) -> Vec<String> {
+    if max_width == 0 || display_width(word) <= max_width {
+        return vec![word.to_string()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut current_chunk = String::new();
+    let mut current_width = 0usize;
+
+    for current_char in word.chars() {
+        let char_width = char_display_width(current_char);
+        if current_width + char_width > max_width {
+            chunks.push(std::mem::take(&mut current_chunk));
+            current_width = 0;
+        }
+        current_chunk.push(current_char);
+        current_width += char_width;
+    }
+    if !current_chunk.is_empty() {
+        chunks.push(current_chunk);
+    }
+
+    chunks
+}
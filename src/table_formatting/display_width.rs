@@ -0,0 +1,109 @@
+//! Measuring how many terminal columns a string occupies.
+//!
+//! A terminal does not draw one column per `char`: combining marks stack on
+//! top of the previous cell (zero columns) and most CJK ideographs, Hangul
+//! syllables, and emoji are drawn two cells wide. The rest of
+//! `table_formatting` measures and pads cells with [`display_width`] instead
+//! of `chars().count()` so wide and combining characters don't misalign the
+//! grid borders.
+//!
+//! This crate has no dependencies, so the East Asian Width ranges below are
+//! approximated by hand rather than by pulling in a dedicated width table
+//! crate; they cover the common CJK/emoji/combining-mark cases but aren't a
+//! full Unicode width implementation.
+
+/// Returns the number of terminal columns a single Unicode scalar occupies.
+///
+/// Approximates the scalar's East Asian Width property: combining marks and
+/// other zero-width code points occupy no columns, wide/fullwidth code
+/// points (CJK ideographs, Hangul syllables, most emoji) occupy two columns,
+/// and everything else occupies one.
+pub fn char_display_width(c: char) -> usize {
+    let code_point = c as u32;
+
+    if is_zero_width(code_point) {
+        0
+    } else if is_wide(code_point) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the number of terminal columns `s` occupies.
+///
+/// `s` is split on `\n` first, so the width of a multi-line string is the
+/// width of its widest line, matching how the grid sizes a cell. ANSI SGR
+/// escape sequences (`\x1b[...m`) are skipped, so a styled string measures
+/// the same as its plain text.
+pub fn display_width(s: &str) -> usize {
+    s.split('\n')
+        .map(|line| visible_chars(line).map(char_display_width).sum())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Iterates over `line`'s characters, skipping any `\x1b[...m` SGR escape
+/// sequence as if it weren't there.
+fn visible_chars(line: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = line.chars().peekable();
+
+    std::iter::from_fn(move || loop {
+        match chars.next()? {
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if c == 'm' {
+                        break;
+                    }
+                }
+            }
+            c => return Some(c),
+        }
+    })
+}
+
+fn is_zero_width(code_point: u32) -> bool {
+    matches!(code_point,
+        0x0300..=0x036F
+        | 0x0483..=0x0489
+        | 0x0591..=0x05BD | 0x05BF | 0x05C1..=0x05C2 | 0x05C4..=0x05C5 | 0x05C7
+        | 0x0610..=0x061A
+        | 0x064B..=0x065F | 0x0670
+        | 0x06D6..=0x06DC | 0x06DF..=0x06E4 | 0x06E7..=0x06E8 | 0x06EA..=0x06ED
+        | 0x0711
+        | 0x0730..=0x074A
+        | 0x07A6..=0x07B0
+        | 0x07EB..=0x07F3
+        | 0x0816..=0x0819 | 0x081B..=0x0823 | 0x0825..=0x0827 | 0x0829..=0x082D
+        | 0x0859..=0x085B
+        | 0x08E3..=0x0902 | 0x093A | 0x093C | 0x0941..=0x0948 | 0x094D | 0x0951..=0x0957 | 0x0962..=0x0963
+        | 0x200B..=0x200F
+        | 0x202A..=0x202E
+        | 0x2060..=0x2064
+        | 0x20D0..=0x20FF
+        | 0xFE00..=0xFE0F
+        | 0xFE20..=0xFE2F
+        | 0xFEFF
+    )
+}
+
+fn is_wide(code_point: u32) -> bool {
+    matches!(code_point,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F680..=0x1F6FF
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD
+    )
+}
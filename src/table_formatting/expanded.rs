@@ -0,0 +1,103 @@
+//! Postgres-style "expanded display" (`\x`) rendering: one record at a time
+//! as key/value pairs, rather than one wide grid. Far more readable than a
+//! many-column table in a narrow terminal.
+
+use crate::table_formatting::display_width::display_width;
+use crate::table_formatting::StringTable;
+
+/// A sequence of records, each a list of `(field name, value)` pairs,
+/// rendered one expanded-display block per record instead of a single wide
+/// grid.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::expanded::ExpandedTable;
+/// use formatted_debug::table_formatting::StringTable;
+///
+/// let table = ExpandedTable {
+///     records: vec![vec![
+///         (String::from("id"), String::from("1")),
+///         (String::from("name"), String::from("Robert")),
+///     ]],
+/// };
+///
+/// assert_eq!(
+///     table.to_table(),
+///     vec![
+///         String::from("-[ RECORD 1 ]+-------"),
+///         String::from("id           | 1"),
+///         String::from("name         | Robert"),
+///     ]
+/// );
+/// ```
+pub struct ExpandedTable {
+    pub records: Vec<Vec<(String, String)>>,
+}
+
+impl StringTable for ExpandedTable {
+    fn to_table(&self) -> Vec<String> {
+        generate_expanded_grid(&self.records)
+    }
+}
+
+/// Renders `records` as one expanded-display block per record: a
+/// `-[ RECORD n ]-+----` header, then each field's name (padded to the
+/// widest name across every record) and value, separated by `|`. A
+/// multi-line value's later lines wrap under the value column.
+///
+/// The name column is widened to fit the `-[ RECORD n ]` label itself if
+/// that label is wider than the longest field name, so the header's `+`
+/// always lines up with the `|` on every data row below it.
+pub fn generate_expanded_grid(records: &[Vec<(String, String)>]) -> Vec<String> {
+    let field_name_width = records
+        .iter()
+        .flat_map(|record| record.iter())
+        .map(|(name, _)| display_width(name))
+        .max()
+        .unwrap_or(0);
+
+    let label_width = (1..=records.len())
+        .map(|record_number| display_width(&format!("-[ RECORD {} ]", record_number)))
+        .max()
+        .unwrap_or(0);
+
+    let name_width = field_name_width.max(label_width.saturating_sub(1));
+
+    let mut result = Vec::new();
+
+    for (record_index, record) in records.iter().enumerate() {
+        let value_width = record
+            .iter()
+            .flat_map(|(_, value)| value.split('\n'))
+            .map(display_width)
+            .max()
+            .unwrap_or(0);
+
+        result.push(header_line(record_index + 1, name_width, value_width));
+
+        for (name, value) in record {
+            let mut lines = value.split('\n');
+
+            if let Some(first_line) = lines.next() {
+                result.push(format!("{} | {}", pad_to_width(name, name_width), first_line));
+            }
+            for line in lines {
+                result.push(format!("{} | {}", " ".repeat(name_width), line));
+            }
+        }
+    }
+
+    result
+}
+
+/// `-[ RECORD n ]`, filled with dashes out to the name column's width, a
+/// `+`, then enough trailing dashes to roughly span the value column.
+fn header_line(record_number: usize, name_width: usize, value_width: usize) -> String {
+    let label = format!("-[ RECORD {} ]", record_number);
+    let dash_fill = (name_width + 1).saturating_sub(display_width(&label));
+
+    format!("{}{}+{}", label, "-".repeat(dash_fill), "-".repeat(value_width.max(4) + 1))
+}
+
+fn pad_to_width(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(display_width(text))))
+}
@@ -1,3 +1,4 @@
+use crate::table_formatting::display_width::display_width;
 use crate::table_formatting::StringTable;
 
 /// A struct which holds widths of rectangles that form a grid
@@ -43,86 +44,272 @@ pub struct GridSizes {
 }
 
 impl StringTable for GridSizes {
+    /// Renders the grid using the default [`BorderStyle`] (the heavy
+    /// box-drawing set). Use [`GridSizes::to_table_with_style`] to pick a
+    /// different one.
     fn to_table(&self) -> Vec<String> {
-        let mut result = vec![self.generate_top_string()];
+        self.to_table_with_style(&BorderStyle::default())
+    }
+}
+
+impl GridSizes {
+    /// Derives a `GridSizes` straight from `contents` instead of requiring
+    /// the caller to measure it themselves: each column's width is its
+    /// widest cell's display width plus the two border columns and
+    /// `2 * padding`, and each row's height is its tallest cell's line count
+    /// (split on `\n`) plus the two border rows. Rows may hold fewer cells
+    /// than the widest row; missing cells are treated as empty.
+    ///
+    /// Always satisfies the `widths`/`heights` `>= 2` invariant, even for
+    /// empty cells, a `padding` of 0, or `contents` with no rows or no
+    /// columns at all — those all fall back to a single empty column and/or
+    /// row rather than an empty `widths`/`heights` vector.
+    /// # Example
+    /// ```
+    /// use formatted_debug::table_formatting::grid_formatting::GridSizes;
+    /// use formatted_debug::table_formatting::StringTable;
+    ///
+    /// let contents = vec![
+    ///     vec![String::from("Name"), String::from("Age")],
+    ///     vec![String::from("Robert"), String::from("34")],
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     GridSizes::from_contents(&contents, 0).to_table(),
+    ///     vec![
+    ///         String::from("┏━━━━━━┳━━━┓"),
+    ///         String::from("┃      ┃   ┃"),
+    ///         String::from("┣━━━━━━╋━━━┫"),
+    ///         String::from("┃      ┃   ┃"),
+    ///         String::from("┗━━━━━━┻━━━┛"),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// Empty `contents` doesn't panic; it renders a single empty cell:
+    /// ```
+    /// use formatted_debug::table_formatting::grid_formatting::GridSizes;
+    /// use formatted_debug::table_formatting::StringTable;
+    ///
+    /// assert_eq!(
+    ///     GridSizes::from_contents(&[], 0).to_table(),
+    ///     vec![
+    ///         String::from("┏┓"),
+    ///         String::from("┃┃"),
+    ///         String::from("┗┛"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_contents(contents: &[Vec<String>], padding: usize) -> GridSizes {
+        let column_count = contents.iter().map(|row| row.len()).max().unwrap_or(0).max(1);
+        let mut widths = vec![2 + 2 * padding; column_count];
+
+        for row in contents {
+            for (column_index, cell) in row.iter().enumerate() {
+                let needed = display_width(cell) + 2 + 2 * padding;
+                widths[column_index] = widths[column_index].max(needed);
+            }
+        }
+
+        let heights = if contents.is_empty() {
+            vec![3]
+        } else {
+            contents
+                .iter()
+                .map(|row| {
+                    let max_lines = row.iter().map(|cell| cell.matches('\n').count() + 1).max().unwrap_or(1);
+                    max_lines + 2
+                })
+                .collect()
+        };
+
+        GridSizes { widths, heights }
+    }
+
+    /// Renders the grid using the given [`BorderStyle`] in place of the
+    /// default heavy box-drawing set. Only the glyphs change; the widths and
+    /// heights already fixed by `self` are unaffected.
+    pub fn to_table_with_style(&self, style: &BorderStyle) -> Vec<String> {
+        let mut result = vec![self.generate_top_string(style)];
 
         let max_height_index = self.heights.len() - 1;
-        let column_seperator = self.get_column_seperator();
+        let column_seperator = self.get_column_seperator(style);
 
         for height_index in 0..=max_height_index {
             let current_height = self.heights[height_index];
 
-            result.append(&mut self.generate_columns(current_height));
+            result.append(&mut self.generate_columns(current_height, style));
             if height_index != max_height_index {
                 result.push((&column_seperator).to_string());
             }
         }
-        result.push(self.generate_bottom_string());
+        result.push(self.generate_bottom_string(style));
 
         result
     }
-}
 
-impl GridSizes {
     /// retrieves top layer
     /// output looks like: "┏━━━━━━━━━━┳━━━━━┳━━━━━━━┓"
-    fn generate_top_string(&self) -> String {
-        let mut top_layer = format!("┏{}", "━".repeat(self.widths[0] - 2));
+    fn generate_top_string(&self, style: &BorderStyle) -> String {
+        let mut top_layer = format!("{}{}", style.top_left, style.horizontal.to_string().repeat(self.widths[0] - 2));
 
         for i in 1..=self.widths.len() - 1 {
-            top_layer += "┳";
-            top_layer += &"━".repeat(self.widths[i] - 2);
+            top_layer.push(style.top_mid);
+            top_layer += &style.horizontal.to_string().repeat(self.widths[i] - 2);
         }
 
-        top_layer += "┓";
+        top_layer.push(style.top_right);
         top_layer
     }
 
     /// retrieves bottom layer
     /// output looks like: "┗━━━━━━━━━━┻━━━━━┻━━━━━━━┛"
-    fn generate_bottom_string(&self) -> String {
-        let mut top_layer = format!("┗{}", "━".repeat(self.widths[0] - 2));
+    fn generate_bottom_string(&self, style: &BorderStyle) -> String {
+        let mut bottom_layer = format!("{}{}", style.bottom_left, style.horizontal.to_string().repeat(self.widths[0] - 2));
 
         for i in 1..=self.widths.len() - 1 {
-            top_layer += "┻";
-            top_layer += &"━".repeat(self.widths[i] - 2);
+            bottom_layer.push(style.bottom_mid);
+            bottom_layer += &style.horizontal.to_string().repeat(self.widths[i] - 2);
         }
 
-        top_layer += "┛";
-        top_layer
+        bottom_layer.push(style.bottom_right);
+        bottom_layer
     }
 
-    fn generate_columns(&self, columns_height: usize) -> Vec<String> {
-        vec![self.get_unit_columns(); columns_height - 2]
+    fn generate_columns(&self, columns_height: usize, style: &BorderStyle) -> Vec<String> {
+        vec![self.get_unit_columns(style); columns_height - 2]
     }
 
-    fn get_unit_columns(&self) -> String {
-        let mut unit_column = String::from("┃");
+    fn get_unit_columns(&self, style: &BorderStyle) -> String {
+        let mut unit_column = String::from(style.vertical);
 
         for current_width in self.widths.iter() {
             unit_column += &" ".repeat(current_width - 2);
-            unit_column += "┃";
+            unit_column.push(style.vertical);
         }
 
         unit_column
     }
 
     /// sits in between table entries
-    /// output looks like: "┣━━━━━━━━━━╋━━━━━╋━━━━━━━┫" 
-    fn get_column_seperator(&self) -> String {
-        let mut column_seperator = String::from("┣");
+    /// output looks like: "┣━━━━━━━━━━╋━━━━━╋━━━━━━━┫"
+    fn get_column_seperator(&self, style: &BorderStyle) -> String {
+        let mut column_seperator = String::from(style.mid_left);
         let max_width_index = self.widths.len() - 1;
 
         for width_index in 0..=max_width_index {
             let current_width = self.widths[width_index];
-            column_seperator += &"━".repeat(current_width - 2);
+            column_seperator += &style.horizontal.to_string().repeat(current_width - 2);
 
             if width_index != max_width_index {
-                column_seperator += "╋";
+                column_seperator.push(style.cross);
             }
         }
 
-        column_seperator += "┫";
+        column_seperator.push(style.mid_right);
         column_seperator
     }
+}
+
+/// The eleven glyphs used to draw a grid's borders: the four corners, the
+/// four T-junctions, the cross where a row and column separator meet, and
+/// the horizontal/vertical fill characters.
+///
+/// Swapping the style only changes which characters are drawn; it never
+/// affects the widths/heights [`GridSizes`] has already computed.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::grid_formatting::{BorderStyle, GridSizes};
+///
+/// let grid = GridSizes {
+///     widths: vec![3usize],
+///     heights: vec![3usize],
+/// };
+///
+/// assert_eq!(
+///     grid.to_table_with_style(&BorderStyle::rounded()),
+///     vec![
+///         String::from("╭─╮"),
+///         String::from("│ │"),
+///         String::from("╰─╯"),
+///     ]
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BorderStyle {
+    pub top_left: char,
+    pub top_mid: char,
+    pub top_right: char,
+    pub mid_left: char,
+    pub cross: char,
+    pub mid_right: char,
+    pub bottom_left: char,
+    pub bottom_mid: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+}
+
+impl BorderStyle {
+    /// The heavy box-drawing set this crate has always used.
+    pub const fn heavy() -> BorderStyle {
+        BorderStyle {
+            top_left: '┏', top_mid: '┳', top_right: '┓',
+            mid_left: '┣', cross: '╋', mid_right: '┫',
+            bottom_left: '┗', bottom_mid: '┻', bottom_right: '┛',
+            horizontal: '━', vertical: '┃',
+        }
+    }
+
+    /// Light lines with rounded corners.
+    pub const fn rounded() -> BorderStyle {
+        BorderStyle {
+            top_left: '╭', top_mid: '┬', top_right: '╮',
+            mid_left: '├', cross: '┼', mid_right: '┤',
+            bottom_left: '╰', bottom_mid: '┴', bottom_right: '╯',
+            horizontal: '─', vertical: '│',
+        }
+    }
+
+    /// Plain ASCII, for terminals or fonts without box-drawing glyphs.
+    pub const fn ascii() -> BorderStyle {
+        BorderStyle {
+            top_left: '+', top_mid: '+', top_right: '+',
+            mid_left: '+', cross: '+', mid_right: '+',
+            bottom_left: '+', bottom_mid: '+', bottom_right: '+',
+            horizontal: '-', vertical: '|',
+        }
+    }
+
+    /// The glyph set `psql`'s bordered output (`\pset border 2`) uses.
+    pub const fn psql() -> BorderStyle {
+        BorderStyle::ascii()
+    }
+
+    /// Double lines.
+    pub const fn double() -> BorderStyle {
+        BorderStyle {
+            top_left: '╔', top_mid: '╦', top_right: '╗',
+            mid_left: '╠', cross: '╬', mid_right: '╣',
+            bottom_left: '╚', bottom_mid: '╩', bottom_right: '╝',
+            horizontal: '═', vertical: '║',
+        }
+    }
+
+    /// No visible border, just whitespace where one would be drawn.
+    pub const fn blank() -> BorderStyle {
+        BorderStyle {
+            top_left: ' ', top_mid: ' ', top_right: ' ',
+            mid_left: ' ', cross: ' ', mid_right: ' ',
+            bottom_left: ' ', bottom_mid: ' ', bottom_right: ' ',
+            horizontal: ' ', vertical: ' ',
+        }
+    }
+}
+
+impl Default for BorderStyle {
+    /// The heavy box-drawing set, matching `GridSizes`'s historical output.
+    fn default() -> BorderStyle {
+        BorderStyle::heavy()
+    }
 }
\ No newline at end of file
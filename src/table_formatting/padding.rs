@@ -0,0 +1,68 @@
+//! Blank space reserved on either side of a cell's content, inside its
+//! column's borders.
+
+/// The number of columns of blank space reserved to the left and right of a
+/// cell's text, inside its column's borders.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Padding {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Default for Padding {
+    /// No padding; content sits flush against the column's inner edges,
+    /// matching the grid's historical behavior.
+    fn default() -> Padding {
+        Padding { left: 0, right: 0 }
+    }
+}
+
+/// Per-column padding: a default applied to every column, with optional
+/// overrides for specific column indices.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::padding::{ColumnPadding, Padding};
+///
+/// let padding = ColumnPadding::new(Padding::default())
+///     .set_column(1, Padding { left: 2, right: 1 });
+///
+/// assert_eq!(padding.get(0), Padding::default());
+/// assert_eq!(padding.get(1), Padding { left: 2, right: 1 });
+/// ```
+#[derive(Clone)]
+pub struct ColumnPadding {
+    default: Padding,
+    overrides: Vec<(usize, Padding)>,
+}
+
+impl ColumnPadding {
+    /// Creates a `ColumnPadding` applying `default` to every column.
+    pub fn new(default: Padding) -> ColumnPadding {
+        ColumnPadding { default, overrides: Vec::new() }
+    }
+
+    /// Overrides the padding used for `column`, replacing any previous
+    /// override for that column.
+    pub fn set_column(mut self, column: usize, padding: Padding) -> ColumnPadding {
+        self.overrides.retain(|(existing_column, _)| *existing_column != column);
+        self.overrides.push((column, padding));
+        self
+    }
+
+    /// Returns the padding to use for `column`: its override if one was set,
+    /// otherwise the default.
+    pub fn get(&self, column: usize) -> Padding {
+        self.overrides
+            .iter()
+            .find(|(existing_column, _)| *existing_column == column)
+            .map(|(_, padding)| *padding)
+            .unwrap_or(self.default)
+    }
+}
+
+impl Default for ColumnPadding {
+    /// No padding for every column, matching the grid's historical behavior.
+    fn default() -> ColumnPadding {
+        ColumnPadding::new(Padding::default())
+    }
+}
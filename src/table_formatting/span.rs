@@ -0,0 +1,288 @@
+//! Cells that cover more than one grid slot.
+//!
+//! [`generate_string_grid`](crate::table_formatting::string_grid::generate_string_grid)
+//! and friends assume a strict `[String; U]` rectangle where every cell
+//! occupies exactly one column and one row. [`SpannedCell`] and
+//! [`generate_spanned_grid`] relax that: a cell can declare a `colspan`/
+//! `rowspan` greater than one, and the interior border junctions it covers
+//! are drawn as a continuous line (or left blank, for an interior row
+//! separator) instead of the usual cross/tee.
+
+use crate::table_formatting::display_width::display_width;
+use crate::table_formatting::grid_formatting::BorderStyle;
+use crate::table_formatting::string_grid::map_string_index;
+
+/// A grid cell plus the number of columns and rows it covers.
+///
+/// Rows are given as `Vec<SpannedCell>` rather than a fixed-size array,
+/// since a row containing a spanning cell has fewer cells than it has
+/// columns: a covered slot simply isn't listed.
+#[derive(Clone)]
+pub struct SpannedCell {
+    pub text: String,
+    pub colspan: usize,
+    pub rowspan: usize,
+}
+
+impl SpannedCell {
+    /// A plain, unspanned cell (colspan and rowspan of 1).
+    pub fn new(text: impl Into<String>) -> SpannedCell {
+        SpannedCell { text: text.into(), colspan: 1, rowspan: 1 }
+    }
+
+    /// A cell spanning `colspan` columns and `rowspan` rows, counted from
+    /// the slot it's listed in.
+    pub fn spanning(text: impl Into<String>, colspan: usize, rowspan: usize) -> SpannedCell {
+        SpannedCell { text: text.into(), colspan, rowspan }
+    }
+}
+
+/// Where a cell landed once span coverage is resolved.
+struct Placement {
+    row: usize,
+    col: usize,
+    colspan: usize,
+    rowspan: usize,
+    text: String,
+}
+
+/// Renders `rows` into a grid of `column_count` columns using the default
+/// [`BorderStyle`], merging the interior borders any spanning cell covers.
+///
+/// Each row lists only the cells it starts; a slot already covered by an
+/// earlier row's `rowspan` is skipped automatically.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::span::{generate_spanned_grid, SpannedCell};
+///
+/// let rows = vec![
+///     vec![SpannedCell::spanning("Title", 2, 1)],
+///     vec![SpannedCell::new("a"), SpannedCell::new("bb")],
+/// ];
+///
+/// assert_eq!(
+///     generate_spanned_grid(&rows, 2),
+///     vec![
+///         String::from("┏━━━━━┓"),
+///         String::from("┃Title┃"),
+///         String::from("┣━━┳━━┫"),
+///         String::from("┃a ┃bb┃"),
+///         String::from("┗━━┻━━┛"),
+///     ]
+/// );
+/// ```
+pub fn generate_spanned_grid(rows: &[Vec<SpannedCell>], column_count: usize) -> Vec<String> {
+    let style = BorderStyle::default();
+
+    if rows.is_empty() || column_count == 0 {
+        return vec![
+            format!("{}{}", style.top_left, style.top_right),
+            format!("{}{}", style.bottom_left, style.bottom_right),
+        ];
+    }
+
+    let row_count = rows.len();
+    let (placements, covered_by) = place_cells(rows, column_count);
+    let widths = distribute_widths(&placements, column_count);
+    let heights = distribute_heights(&placements, row_count);
+
+    let col_boundary = boundary_positions(&widths);
+    let row_boundary = boundary_positions(&heights);
+
+    let vertical_present = |row: usize, col_boundary_index: usize| -> bool {
+        if col_boundary_index == 0 || col_boundary_index == column_count {
+            return true;
+        }
+        let left = covered_by[row][col_boundary_index - 1];
+        let right = covered_by[row][col_boundary_index];
+        !(left.is_some() && left == right)
+    };
+    let horizontal_present = |row_boundary_index: usize, col: usize| -> bool {
+        if row_boundary_index == 0 || row_boundary_index == row_count {
+            return true;
+        }
+        let above = covered_by[row_boundary_index - 1][col];
+        let below = covered_by[row_boundary_index][col];
+        !(above.is_some() && above == below)
+    };
+
+    let total_width = col_boundary[column_count] + 1;
+    let total_lines = row_boundary[row_count] + 1;
+    let mut grid = vec![vec![' '; total_width]; total_lines];
+
+    for (row_boundary_index, &line_index) in row_boundary.iter().enumerate() {
+        for col_boundary_index in 0..=column_count {
+            let up = row_boundary_index > 0 && vertical_present(row_boundary_index - 1, col_boundary_index);
+            let down = row_boundary_index < row_count && vertical_present(row_boundary_index, col_boundary_index);
+            let left = col_boundary_index > 0 && horizontal_present(row_boundary_index, col_boundary_index - 1);
+            let right = col_boundary_index < column_count && horizontal_present(row_boundary_index, col_boundary_index);
+
+            grid[line_index][col_boundary[col_boundary_index]] = junction_char(up, down, left, right, &style);
+        }
+
+        for col in 0..column_count {
+            let fill = if horizontal_present(row_boundary_index, col) { style.horizontal } else { ' ' };
+            for cell in &mut grid[line_index][col_boundary[col] + 1..col_boundary[col + 1]] {
+                *cell = fill;
+            }
+        }
+    }
+
+    for row in 0..row_count {
+        for line in &mut grid[row_boundary[row] + 1..row_boundary[row + 1]] {
+            for col_boundary_index in 0..=column_count {
+                line[col_boundary[col_boundary_index]] =
+                    if vertical_present(row, col_boundary_index) { style.vertical } else { ' ' };
+            }
+        }
+    }
+
+    let mut result: Vec<String> = grid.into_iter().map(|line| line.into_iter().collect()).collect();
+
+    for placement in &placements {
+        write_placement_text(&mut result, &col_boundary, &row_boundary, placement);
+    }
+
+    result
+}
+
+/// Walks each row left to right, assigning every listed [`SpannedCell`] the
+/// next free slot and marking the columns/rows its span covers.
+fn place_cells(rows: &[Vec<SpannedCell>], column_count: usize) -> (Vec<Placement>, Vec<Vec<Option<usize>>>) {
+    let row_count = rows.len();
+    let mut covered_by: Vec<Vec<Option<usize>>> = vec![vec![None; column_count]; row_count];
+    let mut placements = Vec::new();
+
+    for (row_index, row_cells) in rows.iter().enumerate() {
+        let mut cells = row_cells.iter();
+        let mut col = 0usize;
+
+        while col < column_count {
+            if covered_by[row_index][col].is_some() {
+                col += 1;
+                continue;
+            }
+
+            let Some(cell) = cells.next() else { break };
+
+            let colspan = cell.colspan.max(1).min(column_count - col);
+            let rowspan = cell.rowspan.max(1).min(row_count - row_index);
+            let placement_index = placements.len();
+
+            for covered_row in covered_by.iter_mut().skip(row_index).take(rowspan) {
+                for slot in covered_row.iter_mut().skip(col).take(colspan) {
+                    *slot = Some(placement_index);
+                }
+            }
+
+            placements.push(Placement { row: row_index, col, colspan, rowspan, text: cell.text.clone() });
+            col += colspan;
+        }
+    }
+
+    (placements, covered_by)
+}
+
+/// Sizes every column from its unspanned cells first, then grows the
+/// columns a spanning cell covers (evenly, with the remainder going to the
+/// earlier columns) if the merged region is still too narrow for it.
+fn distribute_widths(placements: &[Placement], column_count: usize) -> Vec<usize> {
+    let mut widths = vec![2usize; column_count];
+
+    for placement in placements.iter().filter(|placement| placement.colspan == 1) {
+        let needed = display_width(&placement.text) + 2;
+        widths[placement.col] = widths[placement.col].max(needed);
+    }
+
+    for placement in placements.iter().filter(|placement| placement.colspan > 1) {
+        let span = placement.colspan;
+        let covered: usize = widths[placement.col..placement.col + span].iter().sum();
+        let needed = display_width(&placement.text) + span + 1;
+
+        if covered < needed {
+            grow_evenly(&mut widths[placement.col..placement.col + span], needed - covered);
+        }
+    }
+
+    widths
+}
+
+/// The row-height analogue of [`distribute_widths`].
+fn distribute_heights(placements: &[Placement], row_count: usize) -> Vec<usize> {
+    let mut heights = vec![2usize; row_count];
+
+    for placement in placements.iter().filter(|placement| placement.rowspan == 1) {
+        let needed = placement.text.matches('\n').count() + 1 + 2;
+        heights[placement.row] = heights[placement.row].max(needed);
+    }
+
+    for placement in placements.iter().filter(|placement| placement.rowspan > 1) {
+        let span = placement.rowspan;
+        let covered: usize = heights[placement.row..placement.row + span].iter().sum();
+        let needed = placement.text.matches('\n').count() + 1 + span + 1;
+
+        if covered < needed {
+            grow_evenly(&mut heights[placement.row..placement.row + span], needed - covered);
+        }
+    }
+
+    heights
+}
+
+/// Spreads `extra` across `sizes` as evenly as possible, handing the
+/// remainder to the earliest entries.
+fn grow_evenly(sizes: &mut [usize], extra: usize) {
+    let share = extra / sizes.len();
+    let remainder = extra % sizes.len();
+
+    for (index, size) in sizes.iter_mut().enumerate() {
+        *size += share + if index < remainder { 1 } else { 0 };
+    }
+}
+
+/// Converts a widths/heights array into the character/line position of
+/// every boundary between them (and the position just past the last one).
+fn boundary_positions(sizes: &[usize]) -> Vec<usize> {
+    let mut positions = vec![0usize; sizes.len() + 1];
+
+    for (index, size) in sizes.iter().enumerate() {
+        positions[index + 1] = positions[index] + size - 1;
+    }
+
+    positions
+}
+
+/// Picks the box-drawing glyph for a junction from which of its four
+/// directions (up/down/left/right) still carry a line, after any spans have
+/// suppressed the ones they cover.
+fn junction_char(up: bool, down: bool, left: bool, right: bool, style: &BorderStyle) -> char {
+    match (up, down, left, right) {
+        (false, false, false, false) => ' ',
+        (true, true, false, false) => style.vertical,
+        (false, false, true, true) => style.horizontal,
+        (false, true, false, true) => style.top_left,
+        (false, true, true, false) => style.top_right,
+        (true, false, false, true) => style.bottom_left,
+        (true, false, true, false) => style.bottom_right,
+        (false, true, true, true) => style.top_mid,
+        (true, false, true, true) => style.bottom_mid,
+        (true, true, false, true) => style.mid_left,
+        (true, true, true, false) => style.mid_right,
+        (true, true, true, true) => style.cross,
+        (true, false, false, false) | (false, true, false, false) => style.vertical,
+        (false, false, true, false) | (false, false, false, true) => style.horizontal,
+    }
+}
+
+fn write_placement_text(result: &mut [String], col_boundary: &[usize], row_boundary: &[usize], placement: &Placement) {
+    let line_start = row_boundary[placement.row] + 1;
+    let column_start = col_boundary[placement.col] + 1;
+
+    for (line_offset, text_line) in placement.text.split('\n').enumerate() {
+        let current_line = &mut result[line_start + line_offset];
+        let string_x_start = map_string_index(current_line, column_start);
+        let string_x_end = map_string_index(current_line, column_start + display_width(text_line));
+
+        current_line.replace_range(string_x_start..string_x_end, text_line);
+    }
+}
@@ -1,4 +1,9 @@
-use crate::table_formatting::grid_formatting::GridSizes;
+use crate::table_formatting::alignment::{Alignment, ColumnAlignments, HorizontalAlignment, VerticalAlignment};
+use crate::table_formatting::column_width::{bound_to_width, ColumnMaxWidths};
+use crate::table_formatting::display_width::{char_display_width, display_width};
+use crate::table_formatting::grid_formatting::{BorderStyle, GridSizes};
+use crate::table_formatting::padding::{ColumnPadding, Padding};
+use crate::table_formatting::styled_cell::StyledCell;
 use crate::table_formatting::StringTable;
 
 use std::collections::{BTreeMap, HashMap};
@@ -191,16 +196,182 @@ struct GridCoord {
 /// ]);
 /// ```
 pub fn generate_string_grid<const U: usize>(contents: &Vec<[String; U]>) -> Vec<String> {
+    generate_grid(contents, &BorderStyle::default(), &ColumnAlignments::default(), None, None)
+}
+
+/// Same as [`generate_string_grid`], but drawing the borders with the given
+/// [`BorderStyle`] (e.g. [`BorderStyle::rounded`], [`BorderStyle::ascii`])
+/// instead of the default heavy box-drawing set.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::grid_formatting::BorderStyle;
+/// use formatted_debug::table_formatting::string_grid::generate_string_grid_with_style;
+///
+/// let formatted_strings = vec![[String::from("Hi")]];
+///
+/// assert_eq!(
+///     generate_string_grid_with_style(&formatted_strings, &BorderStyle::ascii()),
+///     vec![
+///         String::from("+--+"),
+///         String::from("|Hi|"),
+///         String::from("+--+"),
+///     ]
+/// );
+/// ```
+pub fn generate_string_grid_with_style<const U: usize>(
+    contents: &Vec<[String; U]>,
+    style: &BorderStyle,
+) -> Vec<String> {
+    generate_grid(contents, style, &ColumnAlignments::default(), None, None)
+}
+
+/// Same as [`generate_string_grid`], but placing each column's content
+/// according to the given [`ColumnAlignments`] instead of always aligning to
+/// the top-left.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::alignment::{Alignment, ColumnAlignments, HorizontalAlignment, VerticalAlignment};
+/// use formatted_debug::table_formatting::string_grid::generate_string_grid_with_alignment;
+///
+/// let formatted_strings = vec![[String::from("x"), String::from("42")]];
+/// let alignments = ColumnAlignments::new(Alignment::default()).set_column(
+///     1,
+///     Alignment { horizontal: HorizontalAlignment::Right, vertical: VerticalAlignment::Top },
+/// );
+///
+/// assert_eq!(
+///     generate_string_grid_with_alignment(&formatted_strings, &alignments),
+///     vec![
+///         String::from("┏━┳━━┓"),
+///         String::from("┃x┃42┃"),
+///         String::from("┗━┻━━┛"),
+///     ]
+/// );
+/// ```
+pub fn generate_string_grid_with_alignment<const U: usize>(
+    contents: &Vec<[String; U]>,
+    alignments: &ColumnAlignments,
+) -> Vec<String> {
+    generate_grid(contents, &BorderStyle::default(), alignments, None, None)
+}
+
+/// Same as [`generate_string_grid`], but bounding each column to the max
+/// width in `max_widths`, truncating or wrapping content that doesn't fit
+/// per its [`OverflowPolicy`](crate::table_formatting::column_width::OverflowPolicy).
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::column_width::{ColumnMaxWidths, OverflowPolicy};
+/// use formatted_debug::table_formatting::string_grid::generate_string_grid_with_max_width;
+///
+/// let formatted_strings = vec![[String::from("a very long sentence")]];
+/// let max_widths = ColumnMaxWidths::new(OverflowPolicy::Truncate).set_default(8);
+///
+/// assert_eq!(
+///     generate_string_grid_with_max_width(&formatted_strings, &max_widths),
+///     vec![
+///         String::from("┏━━━━━━━━┓"),
+///         String::from("┃a very …┃"),
+///         String::from("┗━━━━━━━━┛"),
+///     ]
+/// );
+/// ```
+pub fn generate_string_grid_with_max_width<const U: usize>(
+    contents: &Vec<[String; U]>,
+    max_widths: &ColumnMaxWidths,
+) -> Vec<String> {
+    generate_grid(contents, &BorderStyle::default(), &ColumnAlignments::default(), Some(max_widths), None)
+}
+
+/// Same as [`generate_string_grid`], but taking [`StyledCell`]s instead of
+/// plain `String`s so cells can carry ANSI styling. Each cell's embedded
+/// escape sequences are written into the grid verbatim, while width/height
+/// sizing measures only the visible text.
+/// # Example
+/// ```
+/// use formatted_debug::string_stylizing::{StringColor, StringStyle};
+/// use formatted_debug::table_formatting::styled_cell::StyledCell;
+/// use formatted_debug::table_formatting::string_grid::generate_string_grid_with_styles;
+///
+/// let formatted_strings = vec![[
+///     StyledCell::with_style("hi", StringStyle::default().set_text_color(&StringColor::Red)),
+/// ]];
+///
+/// assert_eq!(
+///     generate_string_grid_with_styles(&formatted_strings),
+///     vec![
+///         String::from("┏━━┓"),
+///         format!("┃{}┃", "\x1b[31mhi\x1b[0m"),
+///         String::from("┗━━┛"),
+///     ]
+/// );
+/// ```
+pub fn generate_string_grid_with_styles<const U: usize>(contents: &Vec<[StyledCell; U]>) -> Vec<String> {
+    let rendered: Vec<[String; U]> = contents
+        .iter()
+        .map(|row| std::array::from_fn(|column_index| row[column_index].render()))
+        .collect();
+
+    generate_grid(&rendered, &BorderStyle::default(), &ColumnAlignments::default(), None, None)
+}
+
+/// Same as [`generate_string_grid`], but reserving the given [`ColumnPadding`]
+/// of blank space on either side of each column's content.
+/// # Example
+/// ```
+/// use formatted_debug::table_formatting::padding::{ColumnPadding, Padding};
+/// use formatted_debug::table_formatting::string_grid::generate_string_grid_with_padding;
+///
+/// let formatted_strings = vec![[String::from("x")]];
+/// let padding = ColumnPadding::new(Padding { left: 1, right: 1 });
+///
+/// assert_eq!(
+///     generate_string_grid_with_padding(&formatted_strings, &padding),
+///     vec![
+///         String::from("┏━━━┓"),
+///         String::from("┃ x ┃"),
+///         String::from("┗━━━┛"),
+///     ]
+/// );
+/// ```
+pub fn generate_string_grid_with_padding<const U: usize>(
+    contents: &Vec<[String; U]>,
+    padding: &ColumnPadding,
+) -> Vec<String> {
+    generate_grid(contents, &BorderStyle::default(), &ColumnAlignments::default(), None, Some(padding))
+}
+
+fn generate_grid<const U: usize>(
+    contents: &Vec<[String; U]>,
+    style: &BorderStyle,
+    alignments: &ColumnAlignments,
+    max_widths: Option<&ColumnMaxWidths>,
+    padding: Option<&ColumnPadding>,
+) -> Vec<String> {
     if contents.is_empty() {
-        return vec![String::from("┏┓"), String::from("┗┛")];
+        return vec![
+            format!("{}{}", style.top_left, style.top_right),
+            format!("{}{}", style.bottom_left, style.bottom_right),
+        ];
     }
 
+    let bounded_contents;
+    let contents = match max_widths {
+        Some(max_widths) => {
+            bounded_contents = bound_column_widths(contents, max_widths);
+            &bounded_contents
+        }
+        None => contents,
+    };
+
+    let default_padding = ColumnPadding::default();
+    let padding = padding.unwrap_or(&default_padding);
+
     let grid = GridSizes {
-        widths: get_max_widths(contents),
+        widths: get_max_widths(contents, padding),
         heights: get_max_heights(contents),
     };
 
-    let mut result = grid.to_table();
+    let mut result = grid.to_table_with_style(style);
 
     for (row_index, row) in contents.iter().enumerate() {
         for (column_index, column) in row.iter().enumerate().take(U) {
@@ -212,6 +383,8 @@ pub fn generate_string_grid<const U: usize>(contents: &Vec<[String; U]>) -> Vec<
                     x: column_index,
                     y: row_index,
                 },
+                alignments.get(column_index),
+                padding.get(column_index),
             )
         }
     }
@@ -219,14 +392,34 @@ pub fn generate_string_grid<const U: usize>(contents: &Vec<[String; U]>) -> Vec<
     result
 }
 
-/// Get the max width of each column
-fn get_max_widths<const U: usize>(contents: &Vec<[String; U]>) -> Vec<usize> {
+/// Truncates or wraps each cell so no column exceeds its configured max
+/// width. Wrapping a cell grows its line count, which downstream
+/// `get_max_heights` picks up the same way it would any other multi-line
+/// cell.
+fn bound_column_widths<const U: usize>(
+    contents: &Vec<[String; U]>,
+    max_widths: &ColumnMaxWidths,
+) -> Vec<[String; U]> {
+    contents
+        .iter()
+        .map(|row| {
+            std::array::from_fn(|column_index| match max_widths.get(column_index) {
+                Some(max_width) => bound_to_width(&row[column_index], max_width, max_widths.policy()),
+                None => row[column_index].clone(),
+            })
+        })
+        .collect()
+}
+
+/// Get the max width of each column, including its configured padding
+fn get_max_widths<const U: usize>(contents: &Vec<[String; U]>, padding: &ColumnPadding) -> Vec<usize> {
     let mut widths = Vec::new();
 
     for column_index in 0..U {
+        let column_padding = padding.get(column_index);
         let mut max_width = 0usize;
         for row in contents.iter() {
-            let current_width = get_string_width(&row[column_index]) + 2;
+            let current_width = get_string_width(&row[column_index]) + 2 + column_padding.left + column_padding.right;
             max_width = std::cmp::max(max_width, current_width);
         }
         widths.push(max_width);
@@ -252,36 +445,52 @@ fn get_max_heights<const U: usize>(contents: &Vec<[String; U]>) -> Vec<usize> {
 }
 
 /// Pain and Suffering
-/// 
+///
 /// Given:
 /// * an inserted text,
 /// * lines which represent a formatted grid
 /// * the grid sizes,
 /// * the replacement coordinates
-/// 
+/// * the alignment to place the text with inside that cell
+/// * the padding reserved on either side of that cell's content
+///
 /// insert the text into the grid, and return the modified lines
 fn insert_text(
     inserted_text: &str,
     original_lines: &mut [String],
     grid: &GridSizes,
     replacement_coords: GridCoord,
+    alignment: Alignment,
+    padding: Padding,
 ) -> Vec<String> {
+    let text_lines: Vec<&str> = inserted_text.split('\n').collect();
+
     //get changed y values
-    let string_y_start = get_replaced_dimension_index(&grid.heights, replacement_coords.y);
-    let string_y_end = string_y_start + get_string_height(inserted_text);
+    let row_start = get_replaced_dimension_index(&grid.heights, replacement_coords.y);
+    let inner_height = grid.heights[replacement_coords.y] - 2;
+    let row_offset = match alignment.vertical {
+        VerticalAlignment::Top => 0,
+        VerticalAlignment::Bottom => inner_height - text_lines.len(),
+        VerticalAlignment::Center => (inner_height - text_lines.len()) / 2,
+    };
+
     //find changed x value assuming non-UTF8
-    let string_x_start = get_replaced_dimension_index(&grid.widths, replacement_coords.x);
+    let column_start = get_replaced_dimension_index(&grid.widths, replacement_coords.x) + padding.left;
+    let inner_width = grid.widths[replacement_coords.x] - 2 - padding.left - padding.right;
+
+    for (line_index, inserted_line) in text_lines.into_iter().enumerate() {
+        let current_line = &mut original_lines[row_start + row_offset + line_index];
 
-    let changed_lines = &mut original_lines[string_y_start..string_y_end];
+        let text_width = get_string_width(inserted_line);
+        let column_offset = match alignment.horizontal {
+            HorizontalAlignment::Left => 0,
+            HorizontalAlignment::Right => inner_width - text_width,
+            HorizontalAlignment::Center => (inner_width - text_width) / 2,
+        };
 
-    for (line_index, current_line) in changed_lines.iter_mut().enumerate() {
-        let inserted_line = inserted_text.split('\n').nth(line_index).unwrap();
         //convert x start and end to be UTF8 friendly
-        let string_x_end = map_string_index(
-            current_line,
-            string_x_start + get_string_width(&String::from(inserted_line)),
-        );
-        let string_x_start = map_string_index(current_line, string_x_start);
+        let string_x_start = map_string_index(current_line, column_start + column_offset);
+        let string_x_end = map_string_index(current_line, column_start + column_offset + text_width);
 
         current_line.replace_range(string_x_start..string_x_end, inserted_line);
     }
@@ -289,8 +498,38 @@ fn insert_text(
     original_lines.to_vec()
 }
 
-fn map_string_index(controlling_string: &str, index: usize) -> usize {
-    controlling_string.char_indices().nth(index).unwrap().0
+/// Maps a display-column offset into `controlling_string` to the matching
+/// byte offset.
+///
+/// This is not a plain `nth` char lookup: once wide or zero-width characters
+/// are involved, a display column no longer corresponds to the same-numbered
+/// `char`, so the column count is accumulated one character at a time. ANSI
+/// SGR escape sequences (`\x1b[...m`) are skipped rather than counted, the
+/// same as [`display_width`] measures them, so locating a splice point in an
+/// already-styled line doesn't drift once earlier columns in that line hold
+/// escape bytes.
+pub(crate) fn map_string_index(controlling_string: &str, index: usize) -> usize {
+    let mut column = 0usize;
+    let mut chars = controlling_string.char_indices().peekable();
+
+    while let Some((byte_index, current_char)) = chars.next() {
+        if current_char == '\x1b' && chars.peek().map(|(_, c)| *c) == Some('[') {
+            chars.next();
+            for (_, c) in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        if column >= index {
+            return byte_index;
+        }
+        column += char_display_width(current_char);
+    }
+
+    controlling_string.len()
 }
 
 fn get_replaced_dimension_index(dimension: &[usize], coord: usize) -> usize {
@@ -307,17 +546,11 @@ fn get_replaced_dimension_index(dimension: &[usize], coord: usize) -> usize {
     panic!("coord value of {} could not be reached", coord)
 }
 
+/// Returns the number of terminal columns `s` occupies, so callers never
+/// assume one column per `char` (full-width CJK/emoji take two columns,
+/// combining marks take zero).
 fn get_string_width(s: &str) -> usize {
-    let mut max_width = 0usize;
-
-    for current_line in s.split('\n') {
-        let current_width = current_line.chars().count();
-        if current_width > max_width {
-            max_width = current_width
-        }
-    }
-
-    max_width
+    display_width(s)
 }
 
 fn get_string_height(s: &str) -> usize {
@@ -0,0 +1,42 @@
+//! Table cells that carry an optional [`StringStyle`], so a grid can hold
+//! colored cells without the embedded ANSI escapes throwing off the column
+//! widths the rest of `table_formatting` computes.
+
+use crate::string_stylizing::{format_string, StringStyle};
+
+/// A cell's text, paired with the style (if any) it should be rendered
+/// with.
+#[derive(Clone)]
+pub struct StyledCell {
+    text: String,
+    style: Option<StringStyle>,
+}
+
+impl StyledCell {
+    /// A cell with no style applied; renders identically to a plain
+    /// `String` cell.
+    pub fn new(text: impl Into<String>) -> StyledCell {
+        StyledCell { text: text.into(), style: None }
+    }
+
+    /// A cell whose text is rendered with `style` applied.
+    pub fn with_style(text: impl Into<String>, style: StringStyle) -> StyledCell {
+        StyledCell { text: text.into(), style: Some(style) }
+    }
+
+    /// Renders this cell's text, applying its style to each line
+    /// independently so every line is self-contained: a styled line always
+    /// opens with the SGR prefix and ends with a reset, regardless of where
+    /// the grid later slices a multi-line cell into separate rows.
+    pub(crate) fn render(&self) -> String {
+        match &self.style {
+            Some(style) => self
+                .text
+                .split('\n')
+                .map(|line| format_string(&line.to_string(), style))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            None => self.text.clone(),
+        }
+    }
+}